@@ -0,0 +1,129 @@
+use prelude::v1::*;
+use base::*;
+use task::*;
+use timer_queue::*;
+use units::*;
+use isr::*;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+/// A minimal single-future executor that parks the running [`Task`] instead
+/// of busy-polling.
+///
+/// Build one with [`Executor::new()`] and hand it a future with
+/// [`Executor::block_on()`] or [`Executor::block_on_with_timers()`].
+/// Whenever the future returns `Poll::Pending`, the calling task blocks in
+/// [`Task::wait_for_notification()`] until its [`Waker`] is woken, which
+/// simply issues a task notification; this maps the executor directly onto
+/// the notification machinery tasks already have, and lets ISR handlers
+/// wake it with [`wake_from_isr()`].
+///
+/// An `Executor` holds no per-task state of its own: the task to park is
+/// captured fresh inside `block_on()`/`block_on_with_timers()` on every
+/// call, so the same `Executor` can safely be reused from whichever task
+/// happens to call it, one future at a time.
+///
+/// [`Task`]: struct.Task.html
+/// [`Executor::new()`]: struct.Executor.html#method.new
+/// [`Executor::block_on()`]: struct.Executor.html#method.block_on
+/// [`Executor::block_on_with_timers()`]: struct.Executor.html#method.block_on_with_timers
+/// [`Task::wait_for_notification()`]: struct.Task.html#method.wait_for_notification
+/// [`wake_from_isr()`]: fn.wake_from_isr.html
+pub struct Executor;
+
+impl Executor {
+    /// Create an executor.
+    pub fn new() -> Executor {
+        Executor
+    }
+
+    /// Run `future` to completion on the current task, parking indefinitely
+    /// between polls until it is woken by its `Waker`.
+    ///
+    /// A future that only ever waits on a [`TimerQueue`] wakeup never
+    /// completes this way, since nothing ticks the queue to fire it; use
+    /// [`block_on_with_timers()`] for that.
+    ///
+    /// [`TimerQueue`]: struct.TimerQueue.html
+    /// [`block_on_with_timers()`]: struct.Executor.html#method.block_on_with_timers
+    pub fn block_on<F: Future>(&self, future: F) -> Result<F::Output, FreeRtosError> {
+        self.block_on_with_timers(future, None)
+    }
+
+    /// Like [`block_on()`], but also ticks `timers` between polls and parks
+    /// for exactly as long as its next deadline, so a future waiting on a
+    /// [`TimerQueue`] wakeup is actually driven instead of blocking forever.
+    ///
+    /// [`block_on()`]: struct.Executor.html#method.block_on
+    /// [`TimerQueue`]: struct.TimerQueue.html
+    pub fn block_on_with_timers<F: Future>(&self,
+                                           mut future: F,
+                                           mut timers: Option<&mut TimerQueue>)
+                                           -> Result<F::Output, FreeRtosError> {
+        let task = Arc::new(try!(Task::current()));
+        let waker = task_waker(task.clone());
+        let mut context = Context::from_waker(&waker);
+
+        // The future never moves again once pinned here.
+        let mut future = unsafe { Pin::new_unchecked(&mut future) };
+
+        loop {
+            match future.as_mut().poll(&mut context) {
+                Poll::Ready(value) => return Ok(value),
+                Poll::Pending => {
+                    let timeout = match timers {
+                        Some(ref mut timers) => timers.poll_timers(),
+                        None => Duration::infinite(),
+                    };
+                    let _ = task.wait_for_notification(0, u32::max_value(), timeout);
+                }
+            }
+        }
+    }
+}
+
+/// Wake the executor running on `task` from an interrupt handler.
+///
+/// `Waker::wake()` can't be called from an ISR since it isn't aware of the
+/// `InterruptContext`; use this instead to unpark an `Executor`'s task from
+/// interrupt handlers.
+pub fn wake_from_isr(task: &Task, context: &InterruptContext) -> Result<(), FreeRtosError> {
+    task.notify_from_isr(context, TaskNotification::Increment)
+}
+
+fn task_waker(task: Arc<Task>) -> Waker {
+    unsafe { Waker::from_raw(raw_waker(Arc::into_raw(task) as *const ())) }
+}
+
+fn raw_waker(ptr: *const ()) -> RawWaker {
+    RawWaker::new(ptr, &TASK_WAKER_VTABLE)
+}
+
+unsafe fn clone_raw(ptr: *const ()) -> RawWaker {
+    // Bump the refcount by cloning a temporary `Arc` and forgetting both it
+    // and the original so neither runs its destructor here.
+    let task = Arc::from_raw(ptr as *const Task);
+    let cloned = task.clone();
+    mem::forget(task);
+    mem::forget(cloned);
+    raw_waker(ptr)
+}
+
+unsafe fn wake_raw(ptr: *const ()) {
+    let task = Arc::from_raw(ptr as *const Task);
+    task.notify(TaskNotification::Increment);
+}
+
+unsafe fn wake_by_ref_raw(ptr: *const ()) {
+    let task = Arc::from_raw(ptr as *const Task);
+    task.notify(TaskNotification::Increment);
+    mem::forget(task);
+}
+
+unsafe fn drop_raw(ptr: *const ()) {
+    drop(Arc::from_raw(ptr as *const Task));
+}
+
+static TASK_WAKER_VTABLE: RawWakerVTable =
+    RawWakerVTable::new(clone_raw, wake_raw, wake_by_ref_raw, drop_raw);
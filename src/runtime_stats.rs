@@ -0,0 +1,80 @@
+use prelude::v1::*;
+use base::*;
+use shim::*;
+use task::*;
+use utils::*;
+
+/// A snapshot of one task's run-time statistics, as reported by
+/// `uxTaskGetSystemState`.
+#[derive(Debug, Clone)]
+pub struct TaskStats {
+    /// The task's name.
+    pub name: String,
+    /// The task's state at the time of the snapshot.
+    pub state: TaskState,
+    /// The task's priority at the time of the snapshot.
+    pub priority: TaskPriority,
+    /// The minimum amount of stack space, in words, that has remained free
+    /// for this task since it started running.
+    pub stack_high_water_mark: u32,
+    /// The task's accumulated run-time counter.
+    pub run_time_counter: u32,
+}
+
+impl TaskStats {
+    /// The percentage of the total run time spent in this task, given the
+    /// `total_run_time` returned alongside this snapshot by
+    /// [`RuntimeStats::snapshot()`].
+    ///
+    /// [`RuntimeStats::snapshot()`]: struct.RuntimeStats.html#method.snapshot
+    pub fn percent_of(&self, total_run_time: u32) -> u32 {
+        if total_run_time == 0 {
+            0
+        } else {
+            (self.run_time_counter as u64 * 100 / total_run_time as u64) as u32
+        }
+    }
+}
+
+/// Run-time statistics for every task known to the scheduler.
+pub struct RuntimeStats;
+
+impl RuntimeStats {
+    /// Walk `uxTaskGetSystemState` and return a [`TaskStats`] snapshot for
+    /// every task, along with the total run time accumulated so far.
+    ///
+    /// [`TaskStats`]: struct.TaskStats.html
+    pub fn snapshot() -> (Vec<TaskStats>, u32) {
+        unsafe {
+            let task_count = freertos_rs_get_task_count() as usize;
+            let mut raw_stats = Vec::with_capacity(task_count);
+            raw_stats.resize(task_count, mem::zeroed::<FreeRtosTaskStatusFfi>());
+
+            let mut total_run_time: u32 = 0;
+            let filled = freertos_rs_get_system_state(raw_stats.as_mut_ptr(),
+                                                      raw_stats.len() as FreeRtosUBaseType,
+                                                      &mut total_run_time as *mut _);
+            raw_stats.truncate(filled as usize);
+
+            let stats = raw_stats.iter()
+                .map(|raw| {
+                    TaskStats {
+                        name: str_from_c_string(raw.name).unwrap_or_else(|_| String::new()),
+                        state: TaskState::from_freertos(raw.state),
+                        priority: TaskPriority(raw.priority as u8),
+                        stack_high_water_mark: raw.stack_high_water_mark,
+                        run_time_counter: raw.run_time_counter,
+                    }
+                })
+                .collect();
+
+            (stats, total_run_time)
+        }
+    }
+}
+
+// `FreeRtosTaskStatusFfi`, `freertos_rs_get_task_count()` and
+// `freertos_rs_get_system_state()` are declared in `shim`, alongside the
+// rest of the crate's FFI surface. Its `state` field matches the `u32`
+// width `TaskState::from_freertos()` expects, which is the natural size
+// for the `eTaskState` C enum.
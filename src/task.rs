@@ -12,13 +12,25 @@ pub struct Task {
     task_handle: FreeRtosTaskHandle,
 }
 
-/// Task's execution priority
-#[derive(Debug, Copy, Clone)]
-pub enum TaskPriority {
-    BelowNormal,
-    Normal,
-    AboveNormal,
-    High,
+/// Task's execution priority.
+///
+/// Wraps a raw FreeRTOS priority in the range `0..configMAX_PRIORITIES`,
+/// where a higher number means a higher priority, exactly as FreeRTOS
+/// defines it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TaskPriority(pub u8);
+
+impl TaskPriority {
+    /// A commonly useful below-normal priority.
+    pub const BELOW_NORMAL: TaskPriority = TaskPriority(4);
+    /// The default priority used by [`Task::new()`].
+    ///
+    /// [`Task::new()`]: struct.Task.html#method.new
+    pub const NORMAL: TaskPriority = TaskPriority(5);
+    /// A commonly useful above-normal priority.
+    pub const ABOVE_NORMAL: TaskPriority = TaskPriority(6);
+    /// A commonly useful high priority.
+    pub const HIGH: TaskPriority = TaskPriority(7);
 }
 
 /// Notification to be sent to a task.
@@ -52,12 +64,7 @@ impl TaskNotification {
 
 impl TaskPriority {
     fn to_freertos(&self) -> FreeRtosUBaseType {
-        match *self {
-            TaskPriority::BelowNormal => 6,
-            TaskPriority::Normal => 5,
-            TaskPriority::AboveNormal => 4,
-            TaskPriority::High => 3,
-        }
+        self.0 as FreeRtosUBaseType
     }
 }
 
@@ -68,6 +75,7 @@ pub struct TaskBuilder {
     task_name: String,
     task_stack_size: u16,
     task_priority: TaskPriority,
+    task_core_affinity: Option<u32>,
 }
 
 impl TaskBuilder {
@@ -89,17 +97,29 @@ impl TaskBuilder {
         self
     }
 
+    /// Pin the task to the cores set in `mask` (one bit per core). Only
+    /// meaningful on FreeRTOS SMP ports.
+    pub fn core_affinity(&mut self, mask: u32) -> &mut Self {
+        self.task_core_affinity = Some(mask);
+        self
+    }
+
     /// Start a new task that can't return a value.
     pub fn start<F>(&self, func: F) -> Result<Task, FreeRtosError>
         where F: FnOnce() -> (),
               F: Send + 'static
     {
 
-        Task::spawn(&self.task_name,
+        let task = try!(Task::spawn(&self.task_name,
                     self.task_stack_size,
                     self.task_priority,
-                    func)
+                    func));
+
+        if let Some(mask) = self.task_core_affinity {
+            task.set_core_affinity(mask);
+        }
 
+        Ok(task)
     }
 }
 
@@ -123,7 +143,8 @@ impl Task {
         TaskBuilder {
             task_name: "rust_task".into(),
             task_stack_size: 1024,
-            task_priority: TaskPriority::Normal,
+            task_priority: TaskPriority::NORMAL,
+            task_core_affinity: None,
         }
     }
 
@@ -247,6 +268,16 @@ impl Task {
         }
     }
 
+    /// Get the task's current priority.
+    pub fn get_priority(&self) -> TaskPriority {
+        unsafe { TaskPriority(freertos_rs_uxTaskPriorityGet(self.task_handle) as u8) }
+    }
+
+    /// Change the task's priority.
+    pub fn set_priority(&self, priority: TaskPriority) {
+        unsafe { freertos_rs_vTaskPrioritySet(self.task_handle, priority.to_freertos()) }
+    }
+
     /// Take the notification and either clear the notification value or decrement it by one.
     pub fn take_notification(&self, clear: bool, wait_for: Duration) -> u32 {
         unsafe { freertos_rs_task_notify_take(if clear { 1 } else { 0 }, wait_for.to_ticks()) }
@@ -272,6 +303,118 @@ impl Task {
             }
         }
     }
+
+    /// Suspend this task. It won't be scheduled again until [`resume()`] or
+    /// [`resume_from_isr()`] is called on it.
+    ///
+    /// [`resume()`]: struct.Task.html#method.resume
+    /// [`resume_from_isr()`]: struct.Task.html#method.resume_from_isr
+    pub fn suspend(&self) {
+        unsafe { freertos_rs_vTaskSuspend(self.task_handle) }
+    }
+
+    /// Resume a task previously suspended with [`suspend()`].
+    ///
+    /// [`suspend()`]: struct.Task.html#method.suspend
+    pub fn resume(&self) {
+        unsafe { freertos_rs_vTaskResume(self.task_handle) }
+    }
+
+    /// Resume a task previously suspended with [`suspend()`], from an
+    /// interrupt handler.
+    ///
+    /// [`suspend()`]: struct.Task.html#method.suspend
+    pub fn resume_from_isr(&self, context: &InterruptContext) {
+        unsafe {
+            freertos_rs_xTaskResumeFromISR(self.task_handle, context.get_task_field_mut());
+        }
+    }
+
+    /// Kick this task out of a blocking wait (for example,
+    /// `wait_for_notification()` or `CurrentTask::delay()`) before its
+    /// timeout has elapsed.
+    ///
+    /// Returns `false` if the task wasn't in the Blocked state, in which
+    /// case there was nothing to abort.
+    pub fn abort_delay(&self) -> bool {
+        unsafe { freertos_rs_xTaskAbortDelay(self.task_handle) != 0 }
+    }
+
+    /// Get the minimum amount of stack space, in words, that has remained
+    /// for this task since it started running. A value close to zero means
+    /// the task's stack is too small.
+    pub fn get_stack_high_water_mark(&self) -> u32 {
+        unsafe { freertos_rs_uxTaskGetStackHighWaterMark(self.task_handle) as u32 }
+    }
+
+    /// Get the task's current state.
+    pub fn get_state(&self) -> TaskState {
+        unsafe { TaskState::from_freertos(freertos_rs_eTaskGetState(self.task_handle)) }
+    }
+
+    /// Pin this task to the cores set in `mask` (one bit per core). Only
+    /// meaningful on FreeRTOS SMP ports.
+    pub fn set_core_affinity(&self, mask: u32) {
+        unsafe { freertos_rs_vTaskCoreAffinitySet(self.task_handle, mask) }
+    }
+
+    /// Get the core affinity mask set with [`set_core_affinity()`].
+    ///
+    /// [`set_core_affinity()`]: struct.Task.html#method.set_core_affinity
+    pub fn get_core_affinity(&self) -> u32 {
+        unsafe { freertos_rs_vTaskCoreAffinityGet(self.task_handle) }
+    }
+
+    /// Get the id of the core this task is currently running on, or `None`
+    /// if it isn't currently running.
+    pub fn get_core_id(&self) -> Option<u32> {
+        unsafe {
+            let core_id = freertos_rs_xTaskGetCoreID(self.task_handle);
+            if core_id < 0 {
+                None
+            } else {
+                Some(core_id as u32)
+            }
+        }
+    }
+
+    /// Get this task's run-time counter, which accumulates the amount of
+    /// time it has spent actually running, in units defined by the
+    /// `portGET_RUN_TIME_COUNTER_VALUE` port macro.
+    pub fn get_run_time_counter(&self) -> u32 {
+        unsafe { freertos_rs_ulTaskGetRunTimeCounter(self.task_handle) }
+    }
+}
+
+/// A task's state, as reported by `eTaskGetState`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TaskState {
+    /// Currently running on a core.
+    Running,
+    /// Able to run, but not currently running.
+    Ready,
+    /// Waiting on a delay, queue, semaphore or notification.
+    Blocked,
+    /// Suspended with `suspend()`, won't be scheduled until resumed.
+    Suspended,
+    /// Has been deleted, but its resources haven't been freed yet.
+    Deleted,
+    /// Not a valid task state; reported for a handle `eTaskGetState`
+    /// doesn't recognize.
+    Invalid,
+}
+
+impl TaskState {
+    fn from_freertos(state: u32) -> TaskState {
+        match state {
+            0 => TaskState::Running,
+            1 => TaskState::Ready,
+            2 => TaskState::Blocked,
+            3 => TaskState::Suspended,
+            4 => TaskState::Deleted,
+            _ => TaskState::Invalid,
+        }
+    }
 }
 
 /// Helper methods to be performed on the task that is currently executing.
@@ -287,4 +430,13 @@ impl CurrentTask {
             freertos_rs_vTaskDelay(delay.to_ticks());
         }
     }
+
+    /// Get the total run time accumulated across all tasks since the
+    /// scheduler started, in the same units as
+    /// [`Task::get_run_time_counter()`].
+    ///
+    /// [`Task::get_run_time_counter()`]: struct.Task.html#method.get_run_time_counter
+    pub fn get_total_run_time() -> u32 {
+        unsafe { freertos_rs_get_total_run_time() }
+    }
 }
@@ -0,0 +1,110 @@
+use prelude::v1::*;
+use base::*;
+use task::*;
+use units::*;
+use core::task::Waker;
+
+/// Identifier for a pending timer, returned by [`TimerQueue::register()`].
+///
+/// [`TimerQueue::register()`]: struct.TimerQueue.html#method.register
+pub type TimerId = u64;
+
+/// A timer wheel that lets a single task manage many pending wakeups
+/// cheaply, instead of spawning a `TaskDelay` per deadline.
+///
+/// Entries are kept in a `BTreeMap` keyed on an extended 64-bit tick count
+/// (with a monotonic id as a tie-breaker), so the earliest deadline is
+/// always `map.iter().next()`. Unlike the raw `FreeRtosTickType` the
+/// hardware reports, the extended count never wraps around within the
+/// lifetime of the queue, which is what lets plain `Ord` give the right
+/// answer. A second `BTreeMap` from id to wake tick lets
+/// [`TimerQueue::cancel()`] find an entry without scanning every pending
+/// timer. The owning task calls [`TimerQueue::poll_timers()`] whenever it
+/// wakes up and sleeps for the `Duration` it returns.
+///
+/// [`TimerQueue::cancel()`]: struct.TimerQueue.html#method.cancel
+/// [`TimerQueue::poll_timers()`]: struct.TimerQueue.html#method.poll_timers
+pub struct TimerQueue {
+    timers: BTreeMap<(u64, u64), Waker>,
+    wake_at_by_id: BTreeMap<u64, u64>,
+    next_id: u64,
+    last_tick: FreeRtosTickType,
+    extended_now: u64,
+}
+
+impl TimerQueue {
+    /// Create an empty timer queue.
+    pub fn new() -> TimerQueue {
+        TimerQueue {
+            timers: BTreeMap::new(),
+            wake_at_by_id: BTreeMap::new(),
+            next_id: 0,
+            last_tick: CurrentTask::get_tick_count(),
+            extended_now: 0,
+        }
+    }
+
+    /// Extend the wrapping `FreeRtosTickType` clock into a 64-bit count
+    /// that keeps increasing monotonically across rollovers, by
+    /// accumulating the wrapping delta since the last observation. This
+    /// only requires that less than one full period of the tick counter
+    /// elapses between two calls into this queue; a longer gap is
+    /// undercounted by whole periods.
+    fn extend(&mut self, tick: FreeRtosTickType) -> u64 {
+        let delta = tick.wrapping_sub(self.last_tick) as u64;
+        self.extended_now = self.extended_now.wrapping_add(delta);
+        self.last_tick = tick;
+        self.extended_now
+    }
+
+    /// Register a wakeup `delay` from now, waking `waker` once it elapses.
+    /// Returns an id that can be passed to [`TimerQueue::cancel()`].
+    ///
+    /// [`TimerQueue::cancel()`]: struct.TimerQueue.html#method.cancel
+    pub fn register(&mut self, delay: Duration, waker: Waker) -> TimerId {
+        let id = self.next_id;
+        self.next_id = self.next_id.wrapping_add(1);
+
+        let now = CurrentTask::get_tick_count();
+        let wake_at = self.extend(now).wrapping_add(delay.to_ticks() as u64);
+        self.timers.insert((wake_at, id), waker);
+        self.wake_at_by_id.insert(id, wake_at);
+        id
+    }
+
+    /// Cancel a previously registered timer. Does nothing if it already
+    /// fired or was never registered.
+    pub fn cancel(&mut self, id: TimerId) {
+        if let Some(wake_at) = self.wake_at_by_id.remove(&id) {
+            self.timers.remove(&(wake_at, id));
+        }
+    }
+
+    /// Wake every timer whose deadline has passed, and return how long to
+    /// sleep until the next one. Returns `Duration::infinite()` if the
+    /// queue is empty.
+    pub fn poll_timers(&mut self) -> Duration {
+        let now = self.extend(CurrentTask::get_tick_count());
+
+        loop {
+            let due = match self.timers.keys().next() {
+                Some(&(wake_at, _)) => wake_at <= now,
+                None => return Duration::infinite(),
+            };
+
+            if !due {
+                break;
+            }
+
+            let key = *self.timers.keys().next().unwrap();
+            let waker = self.timers.remove(&key).unwrap();
+            self.wake_at_by_id.remove(&key.1);
+            waker.wake();
+        }
+
+        match self.timers.keys().next() {
+            Some(&(wake_at, _)) => Duration::ticks((wake_at - now) as FreeRtosTickType),
+            None => Duration::infinite(),
+        }
+    }
+}